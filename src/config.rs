@@ -1,3 +1,9 @@
+/// Re-exported so generated code can build `{field}_source` identifiers via
+/// `$crate::paste::paste!`, without requiring callers to depend on `paste`
+/// themselves.
+#[doc(hidden)]
+pub use paste;
+
 /// Generates static getter methods for each config field defined in a struct.
 ///
 /// This macro is used internally by [`config_generator!`] to expose typed access
@@ -41,6 +47,565 @@ macro_rules! __config_field {
     };
 }
 
+/// Where a resolved field's final value came from.
+///
+/// `Supplied` covers any string-keyed map source (`from_hashmap`,
+/// `try_from_hashmap`, the TOML/JSON/YAML loaders, which funnel through
+/// one of those two, and any `with_hashmap(..)` builder layer). `Env` covers `from_env` and any `with_env(..)`
+/// layer in a builder chain. As more dedicated source kinds (e.g. a file
+/// watcher) land, this can gain matching variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldSource {
+    Default,
+    Supplied,
+    Env,
+}
+
+/// Determines a field's [`FieldSource`] using the same presence/parse rules
+/// as `__config_field!`, so the two always agree on what counts as "supplied".
+///
+/// This macro is used internally by [`config_generator!`] during
+/// `.from_hashmap()` and `.try_from_hashmap()` to build the source map
+/// backing `{field}_source()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __config_field_source {
+    ($hash:ident, $key:ident, String) => {
+        if $hash.contains_key(stringify!($key)) {
+            $crate::FieldSource::Supplied
+        } else {
+            $crate::FieldSource::Default
+        }
+    };
+
+    ($hash:ident, $key:ident, $type:ty) => {
+        match $hash.get(stringify!($key)).and_then(|s| s.parse::<$type>().ok()) {
+            Some(_) => $crate::FieldSource::Supplied,
+            None => $crate::FieldSource::Default,
+        }
+    };
+}
+
+/// Determines a field's [`FieldSource`] using the same presence/parse rules
+/// as `__config_field_env!`, so the two always agree on what counts as "set".
+///
+/// This macro is used internally by [`config_generator!`] during
+/// `.from_env()` to build the source map backing `{field}_source()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __config_field_env_source {
+    ($prefix:expr, $key:ident, String) => {
+        if std::env::var(format!("{}_{}", $prefix, stringify!($key).to_uppercase())).is_ok() {
+            $crate::FieldSource::Env
+        } else {
+            $crate::FieldSource::Default
+        }
+    };
+
+    ($prefix:expr, $key:ident, $type:ty) => {
+        match std::env::var(format!("{}_{}", $prefix, stringify!($key).to_uppercase()))
+            .ok()
+            .and_then(|s| s.parse::<$type>().ok())
+        {
+            Some(_) => $crate::FieldSource::Env,
+            None => $crate::FieldSource::Default,
+        }
+    };
+}
+
+/// Determines a field's [`FieldSource`] for a [`ConfigBuilder`] resolution,
+/// using the same last-parseable-source-wins rule as
+/// `__config_field_from_sources!`, so the two always agree on which source
+/// actually won.
+///
+/// This macro is used internally by [`config_generator!`] during
+/// [`ConfigBuilder::build`] to build the source map backing
+/// `{field}_source()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __config_source_from_sources {
+    ($sources:expr, $field:ident, String) => {
+        $sources
+            .iter()
+            .rev()
+            .find_map(|source| source.lookup(stringify!($field)).map(|_| source.field_source()))
+            .unwrap_or($crate::FieldSource::Default)
+    };
+
+    ($sources:expr, $field:ident, $type:ty) => {
+        $sources
+            .iter()
+            .rev()
+            .find_map(|source| {
+                source
+                    .lookup(stringify!($field))
+                    .and_then(|value| value.parse::<$type>().ok())
+                    .map(|_| source.field_source())
+            })
+            .unwrap_or($crate::FieldSource::Default)
+    };
+}
+
+/// Generates `{field}_source()` accessor methods exposing each field's
+/// provenance, as recorded by [`config_generator!`]'s initializers
+/// (`from_hashmap`, `try_from_hashmap`, `from_env` and the builder).
+///
+/// Needs `paste` to build the `{field}_source` identifiers, since
+/// declarative macros can't synthesize new identifiers on their own.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __config_source_getters {
+    ($static_mod:ident, $($field:ident),* $(,)?) => {
+        $crate::paste::paste! {
+            $(
+                pub fn [<$field _source>]() -> $crate::FieldSource {
+                    $static_mod::SOURCES
+                        .get()
+                        .and_then(|sources| sources.get(stringify!($field)))
+                        .copied()
+                        .unwrap_or($crate::FieldSource::Default)
+                }
+            )*
+        }
+    };
+}
+
+/// A single field that failed to parse during `try_from_hashmap`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub key: String,
+    pub value: String,
+    pub expected_type: &'static str,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid value for `{}`: {:?} is not a valid {}",
+            self.key, self.value, self.expected_type
+        )
+    }
+}
+
+/// Aggregated validation failure returned by a generated `try_from_hashmap`,
+/// describing every key that failed to parse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub errors: Vec<FieldError>,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "invalid configuration ({} field(s)):", self.errors.len())?;
+        for error in &self.errors {
+            writeln!(f, "  - {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Failure mode for a generated `try_from_toml_str`/`try_from_json_str`/
+/// `try_from_yaml_str`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigLoadError {
+    /// The input couldn't be parsed as the expected document format at all.
+    InvalidDocument(String),
+    /// The document parsed, but one or more fields failed to parse, same as
+    /// `try_from_hashmap` would report.
+    InvalidFields(ConfigError),
+}
+
+impl std::fmt::Display for ConfigLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigLoadError::InvalidDocument(msg) => write!(f, "invalid configuration document: {msg}"),
+            ConfigLoadError::InvalidFields(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigLoadError {}
+
+impl From<ConfigError> for ConfigLoadError {
+    fn from(err: ConfigError) -> Self {
+        ConfigLoadError::InvalidFields(err)
+    }
+}
+
+/// Extracts a typed field from a `HashMap<String, String>`, collecting a
+/// [`FieldError`] instead of silently falling back on a parse failure.
+///
+/// This macro is used internally by [`config_generator!`] during
+/// `.try_from_hashmap()`. A missing key still falls back to the default
+/// silently, same as `__config_field!`; only a *present but unparsable*
+/// value is treated as an error.
+///
+/// # Behavior
+/// - For `String` types, it clones the value from the map (never fails).
+/// - For other types, it attempts to parse the value using `.parse()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_config_field {
+    ($hash:ident, $key:ident, $default:expr, String) => {
+        match $hash.get(stringify!($key)) {
+            Some(v) => Ok(v.clone()),
+            None => Ok($default),
+        }
+    };
+
+    ($hash:ident, $key:ident, $default:expr, $type:ty) => {
+        match $hash.get(stringify!($key)) {
+            Some(v) => v.parse::<$type>().map_err(|_| $crate::FieldError {
+                key: stringify!($key).to_string(),
+                value: v.clone(),
+                expected_type: stringify!($type),
+            }),
+            None => Ok($default),
+        }
+    };
+}
+
+/// Extracts a typed field from the process environment with a fallback default.
+///
+/// This macro is used internally by [`config_generator!`] during the `.from_env()`
+/// process to parse and assign values to each field based on its type. The
+/// environment variable name is `{PREFIX}_{FIELD}`, with the field name
+/// uppercased and underscores left untouched.
+///
+/// # Behavior
+/// - For `String` types, it clones the value read from the environment.
+/// - For other types, it attempts to parse the value using `.parse()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __config_field_env {
+    ($prefix:expr, $key:ident, $default:expr, String) => {
+        std::env::var(format!("{}_{}", $prefix, stringify!($key).to_uppercase()))
+            .unwrap_or($default)
+    };
+
+    ($prefix:expr, $key:ident, $default:expr, $type:ty) => {
+        std::env::var(format!("{}_{}", $prefix, stringify!($key).to_uppercase()))
+            .ok()
+            .and_then(|s| s.parse::<$type>().ok())
+            .unwrap_or($default)
+    };
+}
+
+/// Walks a layered source list back-to-front and resolves a single field,
+/// used internally by [`config_generator!`] during [`ConfigBuilder::build`].
+///
+/// Per the precedence contract, this picks the *last source whose value for
+/// this key actually parses*, not merely the last source that contains the
+/// key at all — a later source with an unparsable value must not shadow an
+/// earlier source with a valid one.
+///
+/// # Behavior
+/// - For `String` types, any present value parses, so the last source that
+///   contains the key wins.
+/// - For other types, sources are skipped until `.parse()` succeeds.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __config_field_from_sources {
+    ($sources:expr, $field:ident, $default:expr, String) => {
+        $sources
+            .iter()
+            .rev()
+            .find_map(|source| source.lookup(stringify!($field)))
+            .unwrap_or($default)
+    };
+
+    ($sources:expr, $field:ident, $default:expr, $type:ty) => {
+        $sources
+            .iter()
+            .rev()
+            .find_map(|source| {
+                source
+                    .lookup(stringify!($field))
+                    .and_then(|value| value.parse::<$type>().ok())
+            })
+            .unwrap_or($default)
+    };
+}
+
+/// Flattens a parsed TOML document into the `HashMap<String, String>`
+/// representation `__config_field!` already knows how to resolve.
+///
+/// Nested tables are joined with `_` (e.g. `server.port` becomes
+/// `server_port`), since a Rust field identifier can't contain `.` — this
+/// is the separator that actually lets a nested key bind to a field name
+/// the user chose.
+fn flatten_toml(value: &toml::Value, prefix: &str, out: &mut std::collections::HashMap<String, String>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let key = if prefix.is_empty() { key.clone() } else { format!("{prefix}_{key}") };
+                flatten_toml(value, &key, out);
+            }
+        }
+        toml::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        toml::Value::Integer(i) => {
+            out.insert(prefix.to_string(), i.to_string());
+        }
+        toml::Value::Float(f) => {
+            out.insert(prefix.to_string(), f.to_string());
+        }
+        toml::Value::Boolean(b) => {
+            out.insert(prefix.to_string(), b.to_string());
+        }
+        toml::Value::Datetime(dt) => {
+            out.insert(prefix.to_string(), dt.to_string());
+        }
+        toml::Value::Array(_) => {}
+    }
+}
+
+/// Flattens a parsed JSON document the same way [`flatten_toml`] does.
+fn flatten_json(value: &serde_json::Value, prefix: &str, out: &mut std::collections::HashMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() { key.clone() } else { format!("{prefix}_{key}") };
+                flatten_json(value, &key, out);
+            }
+        }
+        serde_json::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        serde_json::Value::Number(n) => {
+            out.insert(prefix.to_string(), n.to_string());
+        }
+        serde_json::Value::Bool(b) => {
+            out.insert(prefix.to_string(), b.to_string());
+        }
+        serde_json::Value::Null | serde_json::Value::Array(_) => {}
+    }
+}
+
+/// Flattens a parsed YAML document the same way [`flatten_toml`] does.
+fn flatten_yaml(value: &serde_yaml::Value, prefix: &str, out: &mut std::collections::HashMap<String, String>) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (key, value) in map {
+                let Some(key) = key.as_str() else { continue };
+                let key = if prefix.is_empty() { key.to_string() } else { format!("{prefix}_{key}") };
+                flatten_yaml(value, &key, out);
+            }
+        }
+        serde_yaml::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        serde_yaml::Value::Number(n) => {
+            out.insert(prefix.to_string(), n.to_string());
+        }
+        serde_yaml::Value::Bool(b) => {
+            out.insert(prefix.to_string(), b.to_string());
+        }
+        serde_yaml::Value::Null | serde_yaml::Value::Sequence(_) | serde_yaml::Value::Tagged(_) => {}
+    }
+}
+
+/// Parses a TOML document string into the flattened string-map
+/// representation used by `from_hashmap`.
+///
+/// `#[doc(hidden)]` because it's only meant to be called from the
+/// `from_toml_str` method that `config_generator!` generates.
+///
+/// # Panics
+/// Panics if `input` is not a valid TOML document. Use `try_from_toml_str`
+/// (backed by [`__try_flatten_toml_str`]) if malformed input is possible.
+#[doc(hidden)]
+pub fn __flatten_toml_str(input: &str) -> std::collections::HashMap<String, String> {
+    let value: toml::Value = toml::from_str(input).expect("invalid TOML document");
+    let mut out = std::collections::HashMap::new();
+    flatten_toml(&value, "", &mut out);
+    out
+}
+
+/// Parses a JSON document string into the flattened string-map
+/// representation used by `from_hashmap`.
+///
+/// # Panics
+/// Panics if `input` is not a valid JSON document. Use `try_from_json_str`
+/// (backed by [`__try_flatten_json_str`]) if malformed input is possible.
+#[doc(hidden)]
+pub fn __flatten_json_str(input: &str) -> std::collections::HashMap<String, String> {
+    let value: serde_json::Value = serde_json::from_str(input).expect("invalid JSON document");
+    let mut out = std::collections::HashMap::new();
+    flatten_json(&value, "", &mut out);
+    out
+}
+
+/// Parses a YAML document string into the flattened string-map
+/// representation used by `from_hashmap`.
+///
+/// # Panics
+/// Panics if `input` is not a valid YAML document. Use `try_from_yaml_str`
+/// (backed by [`__try_flatten_yaml_str`]) if malformed input is possible.
+#[doc(hidden)]
+pub fn __flatten_yaml_str(input: &str) -> std::collections::HashMap<String, String> {
+    let value: serde_yaml::Value = serde_yaml::from_str(input).expect("invalid YAML document");
+    let mut out = std::collections::HashMap::new();
+    flatten_yaml(&value, "", &mut out);
+    out
+}
+
+/// Parses a TOML document string into the flattened string-map
+/// representation used by `try_from_hashmap`, without panicking.
+///
+/// `#[doc(hidden)]` because it's only meant to be called from the
+/// `try_from_toml_str` method that `config_generator!` generates.
+#[doc(hidden)]
+pub fn __try_flatten_toml_str(input: &str) -> Result<std::collections::HashMap<String, String>, ConfigLoadError> {
+    let value: toml::Value =
+        toml::from_str(input).map_err(|e| ConfigLoadError::InvalidDocument(e.to_string()))?;
+    let mut out = std::collections::HashMap::new();
+    flatten_toml(&value, "", &mut out);
+    Ok(out)
+}
+
+/// Parses a JSON document string into the flattened string-map
+/// representation used by `try_from_hashmap`, without panicking.
+#[doc(hidden)]
+pub fn __try_flatten_json_str(input: &str) -> Result<std::collections::HashMap<String, String>, ConfigLoadError> {
+    let value: serde_json::Value =
+        serde_json::from_str(input).map_err(|e| ConfigLoadError::InvalidDocument(e.to_string()))?;
+    let mut out = std::collections::HashMap::new();
+    flatten_json(&value, "", &mut out);
+    Ok(out)
+}
+
+/// Parses a YAML document string into the flattened string-map
+/// representation used by `try_from_hashmap`, without panicking.
+#[doc(hidden)]
+pub fn __try_flatten_yaml_str(input: &str) -> Result<std::collections::HashMap<String, String>, ConfigLoadError> {
+    let value: serde_yaml::Value =
+        serde_yaml::from_str(input).map_err(|e| ConfigLoadError::InvalidDocument(e.to_string()))?;
+    let mut out = std::collections::HashMap::new();
+    flatten_yaml(&value, "", &mut out);
+    Ok(out)
+}
+
+/// A single raw configuration source layered into a [`ConfigBuilder`].
+///
+/// Sources are resolved left-to-right: a field with a parseable value in a
+/// later source overrides the same field from an earlier one (an unparsable
+/// value is skipped), with the compile-time default used only if no source
+/// supplies a parseable value for the key.
+#[doc(hidden)]
+pub enum ConfigSource {
+    Defaults,
+    Hashmap(std::collections::HashMap<String, String>),
+    Env(String),
+}
+
+impl ConfigSource {
+    /// Looks up a field's raw string value in this source, if present.
+    ///
+    /// `#[doc(hidden)]` because it's only meant to be called from the
+    /// `FromConfigSources` impl that `config_generator!` generates, but it
+    /// has to be `pub` since that impl lives in the caller's crate.
+    #[doc(hidden)]
+    pub fn lookup(&self, field: &str) -> Option<String> {
+        match self {
+            ConfigSource::Defaults => None,
+            ConfigSource::Hashmap(map) => map.get(field).cloned(),
+            ConfigSource::Env(prefix) => {
+                std::env::var(format!("{}_{}", prefix, field.to_uppercase())).ok()
+            }
+        }
+    }
+
+    /// The [`FieldSource`] a value resolved from this source should be
+    /// attributed to.
+    ///
+    /// `#[doc(hidden)]` for the same reason as [`ConfigSource::lookup`].
+    #[doc(hidden)]
+    pub fn field_source(&self) -> FieldSource {
+        match self {
+            ConfigSource::Defaults => FieldSource::Default,
+            ConfigSource::Hashmap(_) => FieldSource::Supplied,
+            ConfigSource::Env(_) => FieldSource::Env,
+        }
+    }
+}
+
+/// Resolves a config's fields from a layered list of [`ConfigSource`]s.
+///
+/// `config_generator!` implements this for the struct it generates, so
+/// [`ConfigBuilder::build`] can resolve fields without needing an inherent
+/// impl on a foreign type.
+#[doc(hidden)]
+pub trait FromConfigSources: Sized + 'static {
+    fn from_sources(sources: Vec<ConfigSource>) -> &'static Self;
+}
+
+/// Builder for layering multiple configuration sources before resolving a
+/// config's final field values.
+///
+/// Returned by the generated `$object::builder()` associated function. Chain
+/// `with_defaults`, `with_hashmap`, and `with_env` in precedence order (later
+/// calls win) and finish with `build()`.
+#[doc(hidden)]
+pub struct ConfigBuilder<T> {
+    sources: Vec<ConfigSource>,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> ConfigBuilder<T> {
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Layers in the compile-time defaults. This layer supplies no values,
+    /// so it does not change how fields resolve wherever it sits in the
+    /// chain: a field no other layer supplies falls back to the default
+    /// anyway, and a later `with_defaults()` does not reset fields supplied
+    /// by earlier layers.
+    pub fn with_defaults(mut self) -> Self {
+        self.sources.push(ConfigSource::Defaults);
+        self
+    }
+
+    /// Layers in a `HashMap<String, String>` source.
+    pub fn with_hashmap(mut self, hash: std::collections::HashMap<String, String>) -> Self {
+        self.sources.push(ConfigSource::Hashmap(hash));
+        self
+    }
+
+    /// Layers in an environment-variable source using `{prefix}_FIELD_NAME`.
+    pub fn with_env(mut self, prefix: impl Into<String>) -> Self {
+        self.sources.push(ConfigSource::Env(prefix.into()));
+        self
+    }
+}
+
+impl<T: FromConfigSources> ConfigBuilder<T> {
+    /// Resolves every layered source and initializes the global config.
+    ///
+    /// For each field, the last source in the chain that supplies a
+    /// parseable value wins; if none do, the compile-time default is used.
+    /// This method can only be called once; further calls return the
+    /// already-initialized config.
+    pub fn build(self) -> &'static T {
+        T::from_sources(self.sources)
+    }
+}
+
+impl<T> Default for ConfigBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Generates a reusable static configuration struct with default values and typed getters.
 ///
 /// This macro creates a global, lazily-initialized config object stored inside a `OnceLock`,
@@ -111,6 +676,13 @@ macro_rules! config_generator {
     ($object:ident, $static_name:ident, [$(($field:ident, $type:ty, $default:expr)),* $(,)?]) => {
         static $static_name: std::sync::OnceLock<$object> = std::sync::OnceLock::new();
 
+        #[allow(non_snake_case)]
+        mod $static_name {
+            pub(super) static SOURCES: std::sync::OnceLock<
+                std::collections::HashMap<&'static str, $crate::FieldSource>,
+            > = std::sync::OnceLock::new();
+        }
+
         #[derive(Debug, Clone, PartialEq)]
         pub struct $object {
             $(pub $field: $type),*
@@ -148,12 +720,192 @@ macro_rules! config_generator {
                     return Self::get()
                 }
 
+                let mut sources = std::collections::HashMap::new();
+                $(
+                    sources.insert(stringify!($field), $crate::__config_field_source!(hash, $field, $type));
+                )*
+                let _ = $static_name::SOURCES.set(sources);
+
                 Self::new($( $field ),*)
             }
 
+            /// Initializes the global config with optional overrides, rejecting
+            /// the call outright if any supplied value fails to parse.
+            ///
+            /// Unlike `from_hashmap`, a present-but-invalid value (e.g. an
+            /// unparsable `log_level`) is not silently replaced by its default.
+            /// Instead, every such key is collected into a single
+            /// [`ConfigError`] describing the offending key, its supplied
+            /// value, and the target type, and `Err` is returned without
+            /// initializing the config. A missing key still falls back to the
+            /// default, same as `from_hashmap`.
+            ///
+            /// # Arguments
+            /// - `hash`: An optional `HashMap<String, String>` with override values.
+            ///
+            /// # Returns
+            /// - `Ok` with a reference to the global config if every supplied value parsed
+            /// - `Err` with every field that failed to parse, otherwise
+            pub fn try_from_hashmap(
+                hash: Option<std::collections::HashMap<String, String>>,
+            ) -> Result<&'static $object, $crate::ConfigError> {
+                let hash = hash.unwrap_or_default();
+                $(
+                    let $field: Result<$type, $crate::FieldError> =
+                        $crate::__try_config_field!(hash, $field, $default, $type);
+                )*
+
+                let mut errors = Vec::new();
+                $(
+                    if let Err(ref e) = $field {
+                        errors.push(e.clone());
+                    }
+                )*
+
+                if !errors.is_empty() {
+                    return Err($crate::ConfigError { errors });
+                }
+
+                $(
+                    let $field = $field.expect("checked above");
+                )*
+
+                if $static_name.get().is_some() {
+                    return Ok(Self::get());
+                }
+
+                let mut sources = std::collections::HashMap::new();
+                $(
+                    sources.insert(stringify!($field), $crate::__config_field_source!(hash, $field, $type));
+                )*
+                let _ = $static_name::SOURCES.set(sources);
+
+                Ok(Self::new($( $field ),*))
+            }
+
+            /// Initializes the global config from environment variables.
+            ///
+            /// For each field `foo_bar`, looks up `{prefix}_FOO_BAR` and parses it
+            /// the same way `from_hashmap` does. Missing variables fall back to
+            /// the compile-time default.
+            ///
+            /// This method can only be called once. Any further calls will return
+            /// the already-initialized config.
+            ///
+            /// # Arguments
+            /// - `prefix`: Prefix prepended to each uppercased field name (e.g. `"APP"`).
+            ///
+            /// # Returns
+            /// - A reference to the global config
+            pub fn from_env(prefix: &str) -> &'static $object {
+                $(
+                    let $field: $type = $crate::__config_field_env!(prefix, $field, $default, $type);
+                )*
+
+                if $static_name.get().is_some() {
+                    return Self::get()
+                }
+
+                let mut sources = std::collections::HashMap::new();
+                $(
+                    sources.insert(stringify!($field), $crate::__config_field_env_source!(prefix, $field, $type));
+                )*
+                let _ = $static_name::SOURCES.set(sources);
+
+                Self::new($( $field ),*)
+            }
+
+            /// Initializes the global config from a TOML document string.
+            ///
+            /// Nested tables are flattened into underscore-joined keys
+            /// (`server.port` becomes `server_port`) before running through the
+            /// same field-resolution as `from_hashmap`.
+            ///
+            /// # Panics
+            /// Panics if `input` is not a valid TOML document. Use
+            /// `try_from_toml_str` to get a `Result` instead.
+            pub fn from_toml_str(input: &str) -> &'static $object {
+                Self::from_hashmap(Some($crate::__flatten_toml_str(input)))
+            }
+
+            /// Initializes the global config from a TOML document string,
+            /// rejecting the call instead of panicking if `input` is malformed.
+            ///
+            /// Nested tables are flattened the same way `from_toml_str` does.
+            /// A field that parses out of the document but fails to convert to
+            /// its target type is reported the same way `try_from_hashmap`
+            /// reports it.
+            pub fn try_from_toml_str(input: &str) -> Result<&'static $object, $crate::ConfigLoadError> {
+                let hash = $crate::__try_flatten_toml_str(input)?;
+                Ok(Self::try_from_hashmap(Some(hash))?)
+            }
+
+            /// Initializes the global config from a JSON document string.
+            ///
+            /// Nested objects are flattened into underscore-joined keys
+            /// (`server.port` becomes `server_port`) before running through the
+            /// same field-resolution as `from_hashmap`.
+            ///
+            /// # Panics
+            /// Panics if `input` is not a valid JSON document. Use
+            /// `try_from_json_str` to get a `Result` instead.
+            pub fn from_json_str(input: &str) -> &'static $object {
+                Self::from_hashmap(Some($crate::__flatten_json_str(input)))
+            }
+
+            /// Initializes the global config from a JSON document string,
+            /// rejecting the call instead of panicking if `input` is malformed.
+            ///
+            /// Nested objects are flattened the same way `from_json_str` does.
+            /// A field that parses out of the document but fails to convert to
+            /// its target type is reported the same way `try_from_hashmap`
+            /// reports it.
+            pub fn try_from_json_str(input: &str) -> Result<&'static $object, $crate::ConfigLoadError> {
+                let hash = $crate::__try_flatten_json_str(input)?;
+                Ok(Self::try_from_hashmap(Some(hash))?)
+            }
+
+            /// Initializes the global config from a YAML document string.
+            ///
+            /// Nested mappings are flattened into underscore-joined keys
+            /// (`server.port` becomes `server_port`) before running through the
+            /// same field-resolution as `from_hashmap`.
+            ///
+            /// # Panics
+            /// Panics if `input` is not a valid YAML document. Use
+            /// `try_from_yaml_str` to get a `Result` instead.
+            pub fn from_yaml_str(input: &str) -> &'static $object {
+                Self::from_hashmap(Some($crate::__flatten_yaml_str(input)))
+            }
+
+            /// Initializes the global config from a YAML document string,
+            /// rejecting the call instead of panicking if `input` is malformed.
+            ///
+            /// Nested mappings are flattened the same way `from_yaml_str` does.
+            /// A field that parses out of the document but fails to convert to
+            /// its target type is reported the same way `try_from_hashmap`
+            /// reports it.
+            pub fn try_from_yaml_str(input: &str) -> Result<&'static $object, $crate::ConfigLoadError> {
+                let hash = $crate::__try_flatten_yaml_str(input)?;
+                Ok(Self::try_from_hashmap(Some(hash))?)
+            }
+
+            /// Starts a layered, multi-source initialization.
+            ///
+            /// Chain `with_defaults()`, `with_hashmap(map)`, and `with_env(prefix)`
+            /// in precedence order (later sources override earlier ones for any
+            /// field they supply), then finish with `build()`.
+            pub fn builder() -> $crate::ConfigBuilder<$object> {
+                $crate::ConfigBuilder::new()
+            }
+
             $crate::__config_ref_getters! {
                 $(($field, &'static $type)),*
             }
+
+            $crate::__config_source_getters! {
+                $static_name, $($field),*
+            }
         }
 
         impl Default for &'static $object {
@@ -161,5 +913,177 @@ macro_rules! config_generator {
                 $object::new($( $default ),*)
             }
         }
+
+        impl $crate::FromConfigSources for $object {
+            fn from_sources(sources: Vec<$crate::ConfigSource>) -> &'static $object {
+                $(
+                    let $field: $type = $crate::__config_field_from_sources!(sources, $field, $default, $type);
+                )*
+
+                if $static_name.get().is_some() {
+                    return $object::get()
+                }
+
+                let mut field_sources = std::collections::HashMap::new();
+                $(
+                    field_sources.insert(stringify!($field), $crate::__config_source_from_sources!(sources, $field, $type));
+                )*
+                let _ = $static_name::SOURCES.set(field_sources);
+
+                $object::new($( $field ),*)
+            }
+        }
+    };
+}
+
+/// Generates owned-clone getter methods for each field of a struct produced
+/// by [`dynamic_config_generator!`].
+///
+/// Unlike [`__config_ref_getters!`], these return an owned clone of the
+/// current snapshot's field rather than a `&'static` reference, since the
+/// underlying config can be swapped out for a new snapshot at any time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dynamic_config_getters {
+    ($(($field:ident, $type:ty)),* $(,)?) => {
+        $(
+            pub fn $field() -> $type {
+                Self::get().$field.clone()
+            }
+        )*
+    };
+}
+
+/// Generates a reusable configuration struct whose value can be atomically
+/// swapped out at runtime, instead of being fixed for the life of the process.
+///
+/// This is the dynamic counterpart to [`config_generator!`]. It stores the
+/// config behind an `RwLock<Arc<$object>>` rather than a write-once
+/// `OnceLock<$object>`, so a process can re-read its sources and publish a
+/// new snapshot without restarting.
+///
+/// # Parameters
+/// - `$object`: Name of the struct (e.g., `AppConfig`)
+/// - `$static_name`: Name of the lock holding the current snapshot (e.g., `CONFIG`)
+/// - Field list: A list of `(field_name, field_type, default_value)` tuples
+///
+/// # Features
+/// - Strong typing for each config key
+/// - Global access via static getters that return an owned clone of the field
+/// - Snapshot access via `current()` returning `Arc<$object>`; readers hold the
+///   shared read lock only long enough to clone the `Arc`
+/// - `from_hashmap` may be called more than once; each call publishes a fresh snapshot
+/// - `update_from_hashmap` re-resolves fields against the *current* snapshot, so
+///   keys absent from the new map keep their existing value instead of reverting
+///   to the compile-time default
+///
+/// # Example
+/// ```rust
+/// use std::collections::HashMap;
+/// use macro_keeper::dynamic_config_generator;
+///
+/// dynamic_config_generator!(
+///     AppConfig,
+///     CONFIG,
+///     [
+///         (buffer_capacity, usize, 1024),
+///         (environment, String, "production".to_string())
+///     ]
+/// );
+///
+/// AppConfig::from_hashmap(None);
+/// assert_eq!(AppConfig::buffer_capacity(), 1024);
+///
+/// let mut map = HashMap::new();
+/// map.insert("buffer_capacity".to_string(), "2048".to_string());
+/// AppConfig::update_from_hashmap(Some(map));
+/// assert_eq!(AppConfig::buffer_capacity(), 2048);
+/// assert_eq!(AppConfig::environment(), "production"); // untouched, carried forward
+/// ```
+#[macro_export]
+macro_rules! dynamic_config_generator {
+    ($object:ident, $static_name:ident, [$(($field:ident, $type:ty, $default:expr)),* $(,)?]) => {
+        static $static_name: std::sync::OnceLock<std::sync::RwLock<std::sync::Arc<$object>>> = std::sync::OnceLock::new();
+
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $object {
+            $(pub $field: $type),*
+        }
+
+        impl $object {
+            fn get() -> std::sync::Arc<$object> {
+                $static_name
+                    .get()
+                    .expect("Config not initialized. Did you forget to call from_hashmap()?")
+                    .read()
+                    .expect("Config lock poisoned")
+                    .clone()
+            }
+
+            fn publish($( $field: $type ),*) -> std::sync::Arc<$object> {
+                let snapshot = std::sync::Arc::new(Self { $( $field ),* });
+
+                match $static_name.get() {
+                    Some(lock) => {
+                        *lock.write().expect("Config lock poisoned") = snapshot.clone();
+                    }
+                    None => {
+                        let _ = $static_name.set(std::sync::RwLock::new(snapshot.clone()));
+                    }
+                }
+
+                snapshot
+            }
+
+            /// Resolves the config from the given sources and publishes it as the
+            /// current snapshot. Unlike the write-once `config_generator!`, this
+            /// may be called repeatedly; each call replaces the current snapshot.
+            ///
+            /// # Arguments
+            /// - `hash`: An optional `HashMap<String, String>` with override values.
+            ///
+            /// # Returns
+            /// - An `Arc` to the newly published snapshot
+            pub fn from_hashmap(hash: Option<std::collections::HashMap<String, String>>) -> std::sync::Arc<$object> {
+                let hash = hash.unwrap_or_default();
+                $(
+                    let $field: $type = $crate::__config_field!(hash, $field, $default, $type);
+                )*
+
+                Self::publish($( $field ),*)
+            }
+
+            /// Re-resolves the config against the given overrides layered on top of
+            /// the *current* snapshot, and publishes the result.
+            ///
+            /// Any field whose key is missing or unparsable in `hash` keeps its
+            /// current value rather than falling back to the compile-time default,
+            /// so this is safe to call with a partial map of just the keys that
+            /// changed.
+            ///
+            /// # Arguments
+            /// - `hash`: An optional `HashMap<String, String>` with override values.
+            ///
+            /// # Returns
+            /// - An `Arc` to the newly published snapshot
+            pub fn update_from_hashmap(hash: Option<std::collections::HashMap<String, String>>) -> std::sync::Arc<$object> {
+                let hash = hash.unwrap_or_default();
+                let current = Self::get();
+                $(
+                    let $field: $type = $crate::__config_field!(hash, $field, current.$field.clone(), $type);
+                )*
+
+                Self::publish($( $field ),*)
+            }
+
+            /// Returns the currently published snapshot.
+            pub fn current() -> std::sync::Arc<$object> {
+                Self::get()
+            }
+
+            $crate::__dynamic_config_getters! {
+                $(($field, $type)),*
+            }
+        }
     };
 }
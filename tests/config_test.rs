@@ -1,4 +1,4 @@
-use macro_keeper::config_generator;
+use macro_keeper::{config_generator, dynamic_config_generator};
 use std::{collections::HashMap, str::FromStr};
 
 #[derive(Debug, PartialEq, Clone)]
@@ -159,6 +159,482 @@ fn test_panic_on_double_initialization() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_config_from_env() {
+    config_generator!(
+        AppConfigTest7,
+        CONFIG_TEST_7,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    std::env::set_var("APP_LOG_LEVEL", "Warn");
+    std::env::set_var("APP_BUFFER_CAPACITY", "8192");
+    std::env::set_var("APP_ENVIRONMENT", "staging");
+
+    AppConfigTest7::from_env("APP");
+    assert_eq!(*AppConfigTest7::buffer_capacity(), 8192);
+    assert_eq!(AppConfigTest7::environment(), "staging");
+    assert_eq!(*AppConfigTest7::log_level(), LogLevel::Warn);
+
+    std::env::remove_var("APP_LOG_LEVEL");
+    std::env::remove_var("APP_BUFFER_CAPACITY");
+    std::env::remove_var("APP_ENVIRONMENT");
+}
+
+#[test]
+fn test_config_from_env_missing_vars_use_defaults() {
+    config_generator!(
+        AppConfigTest8,
+        CONFIG_TEST_8,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    AppConfigTest8::from_env("APPTEST8");
+    assert_eq!(*AppConfigTest8::buffer_capacity(), 1024);
+    assert_eq!(AppConfigTest8::environment(), "production");
+    assert_eq!(*AppConfigTest8::log_level(), LogLevel::Info);
+}
+
+#[test]
+fn test_config_builder_precedence_env_overrides_hashmap() {
+    config_generator!(
+        AppConfigTest9,
+        CONFIG_TEST_9,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("log_level".to_string(), "Warn".to_string());
+    map.insert("buffer_capacity".to_string(), "2048".to_string());
+    map.insert("environment".to_string(), "staging".to_string());
+
+    std::env::set_var("BUILDER_TEST9_ENVIRONMENT", "development");
+
+    AppConfigTest9::builder()
+        .with_defaults()
+        .with_hashmap(map)
+        .with_env("BUILDER_TEST9")
+        .build();
+
+    assert_eq!(*AppConfigTest9::buffer_capacity(), 2048);
+    assert_eq!(AppConfigTest9::environment(), "development");
+    assert_eq!(*AppConfigTest9::log_level(), LogLevel::Warn);
+
+    std::env::remove_var("BUILDER_TEST9_ENVIRONMENT");
+}
+
+#[test]
+fn test_config_builder_falls_back_to_defaults() {
+    config_generator!(
+        AppConfigTest10,
+        CONFIG_TEST_10,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    AppConfigTest10::builder().with_defaults().build();
+    assert_eq!(*AppConfigTest10::buffer_capacity(), 1024);
+    assert_eq!(AppConfigTest10::environment(), "production");
+    assert_eq!(*AppConfigTest10::log_level(), LogLevel::Info);
+}
+
+#[test]
+fn test_config_builder_skips_later_source_with_unparsable_value() {
+    config_generator!(
+        AppConfigTest19,
+        CONFIG_TEST_19,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut earlier = HashMap::new();
+    earlier.insert("buffer_capacity".to_string(), "2048".to_string());
+
+    let mut later = HashMap::new();
+    later.insert("buffer_capacity".to_string(), "not-a-number".to_string());
+
+    AppConfigTest19::builder()
+        .with_hashmap(earlier)
+        .with_hashmap(later)
+        .build();
+
+    // The later source's value doesn't parse, so the earlier valid value
+    // should win rather than falling all the way through to the default.
+    assert_eq!(*AppConfigTest19::buffer_capacity(), 2048);
+}
+
+#[test]
+fn test_dynamic_config_from_hashmap() {
+    dynamic_config_generator!(
+        DynamicConfigTest1,
+        DYNAMIC_CONFIG_TEST_1,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("log_level".to_string(), "Debug".to_string());
+    map.insert("buffer_capacity".to_string(), "2048".to_string());
+    map.insert("environment".to_string(), "development".to_string());
+    DynamicConfigTest1::from_hashmap(Some(map));
+
+    assert_eq!(DynamicConfigTest1::buffer_capacity(), 2048);
+    assert_eq!(DynamicConfigTest1::environment(), "development");
+    assert_eq!(DynamicConfigTest1::log_level(), LogLevel::Debug);
+}
+
+#[test]
+fn test_dynamic_config_allows_reinitialization() {
+    dynamic_config_generator!(
+        DynamicConfigTest2,
+        DYNAMIC_CONFIG_TEST_2,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    DynamicConfigTest2::from_hashmap(None);
+    assert_eq!(DynamicConfigTest2::buffer_capacity(), 1024);
+
+    let mut map = HashMap::new();
+    map.insert("buffer_capacity".to_string(), "4096".to_string());
+    DynamicConfigTest2::from_hashmap(Some(map));
+    assert_eq!(DynamicConfigTest2::buffer_capacity(), 4096);
+}
+
+#[test]
+fn test_dynamic_config_update_carries_forward_untouched_fields() {
+    dynamic_config_generator!(
+        DynamicConfigTest3,
+        DYNAMIC_CONFIG_TEST_3,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("environment".to_string(), "staging".to_string());
+    DynamicConfigTest3::from_hashmap(Some(map));
+
+    let mut update = HashMap::new();
+    update.insert("buffer_capacity".to_string(), "8192".to_string());
+    DynamicConfigTest3::update_from_hashmap(Some(update));
+
+    assert_eq!(DynamicConfigTest3::buffer_capacity(), 8192);
+    assert_eq!(DynamicConfigTest3::environment(), "staging");
+    assert_eq!(*DynamicConfigTest3::current(), DynamicConfigTest3 {
+        log_level: LogLevel::Info,
+        buffer_capacity: 8192,
+        environment: "staging".to_string(),
+    });
+}
+
+#[test]
+fn test_config_from_toml_str() {
+    config_generator!(
+        AppConfigTest11,
+        CONFIG_TEST_11,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let toml = r#"
+        log_level = "Warn"
+        buffer_capacity = 4096
+        environment = "staging"
+    "#;
+
+    AppConfigTest11::from_toml_str(toml);
+    assert_eq!(*AppConfigTest11::buffer_capacity(), 4096);
+    assert_eq!(AppConfigTest11::environment(), "staging");
+    assert_eq!(*AppConfigTest11::log_level(), LogLevel::Warn);
+}
+
+#[test]
+fn test_config_from_json_str() {
+    config_generator!(
+        AppConfigTest12,
+        CONFIG_TEST_12,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let json = r#"{
+        "log_level": "Debug",
+        "buffer_capacity": 2048,
+        "environment": "development"
+    }"#;
+
+    AppConfigTest12::from_json_str(json);
+    assert_eq!(*AppConfigTest12::buffer_capacity(), 2048);
+    assert_eq!(AppConfigTest12::environment(), "development");
+    assert_eq!(*AppConfigTest12::log_level(), LogLevel::Debug);
+}
+
+#[test]
+fn test_config_from_yaml_str() {
+    config_generator!(
+        AppConfigTest13,
+        CONFIG_TEST_13,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let yaml = "log_level: Error\nbuffer_capacity: 512\nenvironment: qa\n";
+
+    AppConfigTest13::from_yaml_str(yaml);
+    assert_eq!(*AppConfigTest13::buffer_capacity(), 512);
+    assert_eq!(AppConfigTest13::environment(), "qa");
+    assert_eq!(*AppConfigTest13::log_level(), LogLevel::Error);
+}
+
+#[test]
+fn test_config_from_toml_str_with_nested_table() {
+    config_generator!(
+        AppConfigTest14,
+        CONFIG_TEST_14,
+        [
+            (server_port, usize, 8080),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let toml = r#"
+        environment = "staging"
+
+        [server]
+        port = 9090
+    "#;
+
+    AppConfigTest14::from_toml_str(toml);
+    assert_eq!(*AppConfigTest14::environment(), "staging");
+    // Nested tables flatten to underscore-joined keys, so `[server] port`
+    // binds to the `server_port` field.
+    assert_eq!(*AppConfigTest14::server_port(), 9090);
+}
+
+#[test]
+fn test_try_from_hashmap_ok() {
+    config_generator!(
+        AppConfigTest15,
+        CONFIG_TEST_15,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("log_level".to_string(), "Debug".to_string());
+    map.insert("buffer_capacity".to_string(), "2048".to_string());
+
+    let config = AppConfigTest15::try_from_hashmap(Some(map)).expect("should parse");
+    assert_eq!(config.buffer_capacity, 2048);
+    assert_eq!(config.environment, "production");
+    assert_eq!(config.log_level, LogLevel::Debug);
+}
+
+#[test]
+fn test_try_from_hashmap_reports_all_invalid_fields() {
+    config_generator!(
+        AppConfigTest16,
+        CONFIG_TEST_16,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("log_level".to_string(), "NotALevel".to_string());
+    map.insert("buffer_capacity".to_string(), "not-a-number".to_string());
+
+    let err = AppConfigTest16::try_from_hashmap(Some(map)).expect_err("should reject");
+    assert_eq!(err.errors.len(), 2);
+    assert!(err
+        .errors
+        .iter()
+        .any(|e| e.key == "log_level" && e.value == "NotALevel"));
+    assert!(err
+        .errors
+        .iter()
+        .any(|e| e.key == "buffer_capacity" && e.value == "not-a-number"));
+}
+
+#[test]
+fn test_field_source_tracks_supplied_vs_default() {
+    config_generator!(
+        AppConfigTest17,
+        CONFIG_TEST_17,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("environment".to_string(), "staging".to_string());
+    AppConfigTest17::from_hashmap(Some(map));
+
+    assert_eq!(
+        AppConfigTest17::environment_source(),
+        macro_keeper::FieldSource::Supplied
+    );
+    assert_eq!(
+        AppConfigTest17::buffer_capacity_source(),
+        macro_keeper::FieldSource::Default
+    );
+}
+
+#[test]
+fn test_field_source_falls_back_to_default_on_unparsable_value() {
+    config_generator!(
+        AppConfigTest18,
+        CONFIG_TEST_18,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("buffer_capacity".to_string(), "not-a-number".to_string());
+    AppConfigTest18::from_hashmap(Some(map));
+
+    assert_eq!(*AppConfigTest18::buffer_capacity(), 1024);
+    assert_eq!(
+        AppConfigTest18::buffer_capacity_source(),
+        macro_keeper::FieldSource::Default
+    );
+}
+
+#[test]
+fn test_field_source_tracks_env() {
+    config_generator!(
+        AppConfigTest20,
+        CONFIG_TEST_20,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    std::env::set_var("SOURCE_TEST20_ENVIRONMENT", "development");
+    AppConfigTest20::from_env("SOURCE_TEST20");
+    std::env::remove_var("SOURCE_TEST20_ENVIRONMENT");
+
+    assert_eq!(
+        AppConfigTest20::environment_source(),
+        macro_keeper::FieldSource::Env
+    );
+    assert_eq!(
+        AppConfigTest20::buffer_capacity_source(),
+        macro_keeper::FieldSource::Default
+    );
+}
+
+#[test]
+fn test_field_source_tracks_builder_precedence() {
+    config_generator!(
+        AppConfigTest21,
+        CONFIG_TEST_21,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("buffer_capacity".to_string(), "2048".to_string());
+
+    std::env::set_var("SOURCE_TEST21_ENVIRONMENT", "development");
+    AppConfigTest21::builder()
+        .with_defaults()
+        .with_hashmap(map)
+        .with_env("SOURCE_TEST21")
+        .build();
+    std::env::remove_var("SOURCE_TEST21_ENVIRONMENT");
+
+    assert_eq!(
+        AppConfigTest21::buffer_capacity_source(),
+        macro_keeper::FieldSource::Supplied
+    );
+    assert_eq!(
+        AppConfigTest21::environment_source(),
+        macro_keeper::FieldSource::Env
+    );
+    assert_eq!(
+        AppConfigTest21::log_level_source(),
+        macro_keeper::FieldSource::Default
+    );
+}
+
+#[test]
+fn test_field_source_tracks_try_from_hashmap() {
+    config_generator!(
+        AppConfigTest22,
+        CONFIG_TEST_22,
+        [
+            (log_level, LogLevel, LogLevel::Info),
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let mut map = HashMap::new();
+    map.insert("environment".to_string(), "staging".to_string());
+    AppConfigTest22::try_from_hashmap(Some(map)).expect("should parse");
+
+    assert_eq!(
+        AppConfigTest22::environment_source(),
+        macro_keeper::FieldSource::Supplied
+    );
+    assert_eq!(
+        AppConfigTest22::buffer_capacity_source(),
+        macro_keeper::FieldSource::Default
+    );
+}
+
 #[test]
 fn test_panic_on_calling_before_initialization() {
     config_generator!(
@@ -178,3 +654,74 @@ fn test_panic_on_calling_before_initialization() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_try_from_toml_str_ok() {
+    config_generator!(
+        AppConfigTest23,
+        CONFIG_TEST_23,
+        [
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let toml = r#"
+        environment = "staging"
+        buffer_capacity = 4096
+    "#;
+
+    AppConfigTest23::try_from_toml_str(toml).expect("should parse");
+    assert_eq!(*AppConfigTest23::buffer_capacity(), 4096);
+    assert_eq!(AppConfigTest23::environment(), "staging");
+}
+
+#[test]
+fn test_try_from_toml_str_rejects_malformed_document() {
+    config_generator!(
+        AppConfigTest24,
+        CONFIG_TEST_24,
+        [
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let err = AppConfigTest24::try_from_toml_str("not = [valid toml").expect_err("should reject");
+    assert!(matches!(err, macro_keeper::ConfigLoadError::InvalidDocument(_)));
+}
+
+#[test]
+fn test_try_from_json_str_rejects_unparsable_field() {
+    config_generator!(
+        AppConfigTest25,
+        CONFIG_TEST_25,
+        [
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let json = r#"{ "buffer_capacity": "not-a-number" }"#;
+
+    let err = AppConfigTest25::try_from_json_str(json).expect_err("should reject");
+    assert!(matches!(err, macro_keeper::ConfigLoadError::InvalidFields(_)));
+}
+
+#[test]
+fn test_try_from_yaml_str_ok() {
+    config_generator!(
+        AppConfigTest26,
+        CONFIG_TEST_26,
+        [
+            (buffer_capacity, usize, 1024),
+            (environment, String, "production".to_string())
+        ]
+    );
+
+    let yaml = "buffer_capacity: 512\nenvironment: qa\n";
+
+    AppConfigTest26::try_from_yaml_str(yaml).expect("should parse");
+    assert_eq!(*AppConfigTest26::buffer_capacity(), 512);
+    assert_eq!(AppConfigTest26::environment(), "qa");
+}